@@ -13,27 +13,243 @@ fn meta_to_vec(meta: Meta) -> Result<Vec<NestedMeta>, Meta> {
     }
 }
 
+// Names of the attribute idents `extract_item_from_syn` knows how to handle.
+// `KNOWN_ITEM_ATTRS` and the match in `extract_item_from_syn` both match against
+// these same constants, so the "did you mean" suggestion list can't drift out of
+// sync with the real match arms.
+const ATTR_PYFUNCTION: &str = "pyfunction";
+const ATTR_PYATTR: &str = "pyattr";
+const ATTR_PYCLASS: &str = "pyclass";
+const ATTR_PYSTRUCT_SEQUENCE: &str = "pystruct_sequence";
+const ATTR_CFG: &str = "cfg";
+
+const KNOWN_ITEM_ATTRS: &[&str] = &[
+    ATTR_PYFUNCTION,
+    ATTR_PYATTR,
+    ATTR_PYCLASS,
+    ATTR_PYSTRUCT_SEQUENCE,
+    ATTR_CFG,
+];
+
+/// Standard edit-distance DP between `a` and `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b_len = b.chars().count();
+    let mut row: Vec<usize> = (0..=b_len).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut prev = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.chars().enumerate() {
+            let tmp = row[j + 1];
+            row[j + 1] = std::cmp::min(
+                std::cmp::min(row[j] + 1, row[j + 1] + 1),
+                prev + (ca != cb) as usize,
+            );
+            prev = tmp;
+        }
+    }
+    row[b_len]
+}
+
+/// Finds the closest known attribute name to `name`, if it's plausibly a typo.
+fn suggest_item_attr(name: &str) -> Option<&'static str> {
+    KNOWN_ITEM_ATTRS
+        .iter()
+        .map(|known| (*known, levenshtein(name, known)))
+        .filter(|(_, dist)| *dist <= 2 && *dist * 3 < name.len())
+        .min_by_key(|(_, dist)| *dist)
+        .map(|(known, _)| known)
+}
+
+/// Collects `#[doc = "..."]` fragments, the desugared form of `///` doc comments.
+fn doc_fragments(attrs: &[syn::Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter_map(|attr| attr.parse_meta().ok())
+        .filter_map(|meta| match meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => match nv.lit {
+                syn::Lit::Str(s) => Some(s.value()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Joins doc fragments into a single docstring, stripping the common leading
+/// whitespace the way rustdoc's `attrs_to_doc_fragments` does.
+fn doc_fragments_to_string(frags: Vec<String>) -> Option<String> {
+    if frags.is_empty() {
+        return None;
+    }
+    let lines: Vec<&str> = frags
+        .iter()
+        .map(|frag| frag.strip_prefix(' ').unwrap_or(frag))
+        .collect();
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let doc = lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_else(|| line.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    Some(doc.trim().to_owned())
+}
+
+/// Deprecation metadata parsed from `#[pyfunction(deprecated(since = "...", note = "..."))]`,
+/// mirroring rustdoc's `Deprecation { since, note }`.
+#[derive(Clone)]
+struct Deprecation {
+    since: Option<String>,
+    note: String,
+}
+
+impl Deprecation {
+    fn parse(list: &syn::MetaList) -> Result<Self, Diagnostic> {
+        let mut since = None;
+        let mut note = None;
+        for nested in &list.nested {
+            let nv = match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                other => bail_span!(other, "#[deprecated(...)] only accepts `since` and `note`"),
+            };
+            let value = match &nv.lit {
+                syn::Lit::Str(s) => s.value(),
+                other => bail_span!(other, "#[deprecated(...)] values must be string literals"),
+            };
+            if nv.path.is_ident("since") {
+                since = Some(value);
+            } else if nv.path.is_ident("note") {
+                note = Some(value);
+            } else {
+                bail_span!(nv, "#[deprecated(...)] only accepts `since` and `note`");
+            }
+        }
+        let note = note.ok_or_else(|| err_span!(list, "#[deprecated(...)] requires a `note`"))?;
+        Ok(Deprecation { since, note })
+    }
+}
+
+/// Pulls extra Python names out of `nesteds`: any `name = "..."` beyond the first
+/// (which `ItemMeta` still resolves as the primary name) and any `aliases("...", ...)`
+/// list. Lets a single `#[pyfunction]`/`#[pyattr]` be exposed under several names.
+fn extract_aliases(nesteds: &mut Vec<NestedMeta>) -> Result<Vec<String>, Diagnostic> {
+    let mut aliases = Vec::new();
+    let mut seen_name = false;
+    let mut i = 0;
+    while i < nesteds.len() {
+        let remove = match &nesteds[i] {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                if seen_name {
+                    let value = match &nv.lit {
+                        syn::Lit::Str(s) => s.value(),
+                        other => {
+                            bail_span!(other, "#[pyattr(name = ...)] must be a string literal")
+                        }
+                    };
+                    aliases.push(value);
+                    true
+                } else {
+                    seen_name = true;
+                    false
+                }
+            }
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("aliases") => {
+                for nested in &list.nested {
+                    let value = match nested {
+                        NestedMeta::Lit(syn::Lit::Str(s)) => s.value(),
+                        other => {
+                            bail_span!(
+                                other,
+                                "#[pyattr(aliases(...))] entries must be string literals"
+                            )
+                        }
+                    };
+                    aliases.push(value);
+                }
+                true
+            }
+            _ => false,
+        };
+        if remove {
+            nesteds.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    Ok(aliases)
+}
+
+/// Pulls a `deprecated(...)` nested meta out of `nesteds`, if present, leaving the rest
+/// for `ItemMeta` to parse as usual.
+fn extract_deprecated(nesteds: &mut Vec<NestedMeta>) -> Result<Option<Deprecation>, Diagnostic> {
+    let pos = nesteds.iter().position(|nested| {
+        matches!(nested, NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("deprecated"))
+    });
+    let pos = match pos {
+        Some(pos) => pos,
+        None => return Ok(None),
+    };
+    let list = match nesteds.remove(pos) {
+        NestedMeta::Meta(Meta::List(list)) => list,
+        _ => unreachable!(),
+    };
+    Ok(Some(Deprecation::parse(&list)?))
+}
+
 #[derive(Default)]
 struct Module {
-    items: HashMap<(String, Vec<Meta>), ModuleItem>,
+    /// One entry per distinct `#[py*]` item; each item may be bound under several
+    /// `py_names`, so the dedup key is the `(name, cfgs)` pair, not the item itself.
+    items: Vec<(Vec<Meta>, ModuleItem)>,
+    names: HashMap<(String, Vec<Meta>), ()>,
 }
 
-#[derive(PartialEq, Eq, Hash)]
 enum ModuleItem {
-    Function { item_ident: Ident, py_name: String },
-    EvaluatedAttr { item_ident: Ident, py_name: String },
-    Class { item_ident: Ident, py_name: String },
+    Function {
+        item_ident: Ident,
+        py_names: Vec<String>,
+        deprecation: Option<Deprecation>,
+        doc: Option<String>,
+    },
+    EvaluatedAttr {
+        item_ident: Ident,
+        py_names: Vec<String>,
+        deprecation: Option<Deprecation>,
+        doc: Option<String>,
+    },
+    Class {
+        item_ident: Ident,
+        py_names: Vec<String>,
+        deprecation: Option<Deprecation>,
+        doc: Option<String>,
+    },
 }
 
 impl ModuleItem {
-    fn name(&self) -> String {
+    fn names(&self) -> &[String] {
         use ModuleItem::*;
         match self {
-            Function { py_name, .. } => py_name.clone(),
-            EvaluatedAttr { py_name, .. } => py_name.clone(),
-            Class { py_name, .. } => py_name.clone(),
+            Function { py_names, .. } => py_names,
+            EvaluatedAttr { py_names, .. } => py_names,
+            Class { py_names, .. } => py_names,
         }
     }
+
+    /// Doc comments live on the `syn` item, not on the individual `#[py*]` meta, so
+    /// they're threaded in after construction rather than parsed in `extract_*`.
+    fn with_doc(mut self, new_doc: Option<String>) -> Self {
+        use ModuleItem::*;
+        match &mut self {
+            Function { doc, .. } | EvaluatedAttr { doc, .. } | Class { doc, .. } => {
+                *doc = new_doc;
+            }
+        }
+        self
+    }
 }
 
 impl Module {
@@ -43,50 +259,66 @@ impl Module {
         cfgs: Vec<Meta>,
         span: Span,
     ) -> Result<(), Diagnostic> {
-        if let Some(existing) = self.items.insert((item.name(), cfgs), item) {
-            Err(Diagnostic::span_error(
-                span,
-                format!(
-                    "Duplicate #[py*] attribute on pymodule: {}",
-                    existing.name()
-                ),
-            ))
-        } else {
-            Ok(())
+        for name in item.names() {
+            if self.names.contains_key(&(name.clone(), cfgs.clone())) {
+                return Err(Diagnostic::span_error(
+                    span,
+                    format!("Duplicate #[py*] attribute on pymodule: {}", name),
+                ));
+            }
+        }
+        for name in item.names() {
+            self.names.insert((name.clone(), cfgs.clone()), ());
         }
+        self.items.push((cfgs, item));
+        Ok(())
     }
 
     fn extract_function(ident: &Ident, meta: Meta) -> Result<ModuleItem, Diagnostic> {
-        let nesteds = meta_to_vec(meta).map_err(|meta| {
+        let mut nesteds = meta_to_vec(meta).map_err(|meta| {
             err_span!(
                 meta,
                 "#[pyfunction = \"...\"] cannot be a name/value, you probably meant \
                  #[pyfunction(name = \"...\")]",
             )
         })?;
+        let aliases = extract_aliases(&mut nesteds)?;
+        let deprecation = extract_deprecated(&mut nesteds)?;
 
         let item_meta =
             ItemMeta::from_nested_meta("pyfunction", &ident, &nesteds, ItemMeta::SIMPLE_NAMES)?;
+        let mut py_names = vec![item_meta.simple_name()?];
+        py_names.extend(aliases);
         Ok(ModuleItem::Function {
             item_ident: ident.clone(),
-            py_name: item_meta.simple_name()?,
+            py_names,
+            deprecation,
+            doc: None,
         })
     }
 
+    /// `#[pyclass(deprecated(...))]` is parsed and stored (so `__deprecated__`
+    /// introspection works and tooling can flag it), but unlike functions/attrs no
+    /// runtime `DeprecationWarning` is emitted: that would mean warning from the
+    /// class's `__new__`/`__call__`, which needs hooking into `PyClassImpl` machinery
+    /// this macro doesn't otherwise touch.
     fn extract_class(ident: &Ident, meta: Meta) -> Result<ModuleItem, Diagnostic> {
-        let nesteds = meta_to_vec(meta).map_err(|meta| {
+        let mut nesteds = meta_to_vec(meta).map_err(|meta| {
             err_span!(
                 meta,
                 "#[pyclass = \"...\"] cannot be a name/value, you probably meant \
                  #[pyclass(name = \"...\")]",
             )
         })?;
+        let deprecation = extract_deprecated(&mut nesteds)?;
 
         let item_meta =
             ItemMeta::from_nested_meta("pyclass", &ident, &nesteds, ItemMeta::SIMPLE_NAMES)?;
         Ok(ModuleItem::Class {
             item_ident: ident.clone(),
-            py_name: item_meta.simple_name()?,
+            py_names: vec![item_meta.simple_name()?],
+            deprecation,
+            doc: None,
         })
     }
 
@@ -107,31 +339,41 @@ impl Module {
         )?;
         Ok(ModuleItem::Class {
             item_ident: ident.clone(),
-            py_name: item_meta.simple_name()?,
+            py_names: vec![item_meta.simple_name()?],
+            deprecation: None,
+            doc: None,
         })
     }
 
     fn extract_attr(ident: &Ident, meta: Meta) -> Result<ModuleItem, Diagnostic> {
-        let nesteds = meta_to_vec(meta).map_err(|meta| {
+        let mut nesteds = meta_to_vec(meta).map_err(|meta| {
             err_span!(
                 meta,
                 "#[pyattr = \"...\"] cannot be a name/value, you probably meant \
                  #[pyattr(name = \"...\")]",
             )
         })?;
+        let aliases = extract_aliases(&mut nesteds)?;
+        let deprecation = extract_deprecated(&mut nesteds)?;
 
         let item_meta =
             ItemMeta::from_nested_meta("pyattr", &ident, &nesteds, ItemMeta::SIMPLE_NAMES)?;
+        let mut py_names = vec![item_meta.simple_name()?];
+        py_names.extend(aliases);
         Ok(ModuleItem::EvaluatedAttr {
             item_ident: ident.clone(),
-            py_name: item_meta.simple_name()?,
+            py_names,
+            deprecation,
+            doc: None,
         })
     }
 
     fn extract_item_from_syn(&mut self, item: &mut ItemIdent) -> Result<(), Diagnostic> {
+        let doc = doc_fragments_to_string(doc_fragments(item.attrs));
         let mut attr_idxs = Vec::new();
         let mut items = Vec::new();
         let mut cfgs = Vec::new();
+        let mut unknown_attrs = Vec::new();
         for (i, meta) in item
             .attrs
             .iter()
@@ -144,35 +386,49 @@ impl Module {
                 None => continue,
             };
             match name.to_string().as_str() {
-                "pyfunction" => {
+                ATTR_PYFUNCTION => {
                     assert!(item.typ == ItemType::Fn);
                     attr_idxs.push(i);
                     items.push((Self::extract_function(item.ident, meta)?, meta_span));
                 }
-                "pyattr" => {
+                ATTR_PYATTR => {
                     assert!(item.typ == ItemType::Fn);
                     attr_idxs.push(i);
                     items.push((Self::extract_attr(item.ident, meta)?, meta_span));
                 }
-                "pyclass" => {
+                ATTR_PYCLASS => {
                     assert!(item.typ == ItemType::Struct);
                     items.push((Self::extract_class(item.ident, meta)?, meta_span));
                 }
-                "pystruct_sequence" => {
+                ATTR_PYSTRUCT_SEQUENCE => {
                     assert!(item.typ == ItemType::Struct);
                     items.push((Self::extract_struct_sequence(item.ident, meta)?, meta_span));
                 }
-                "cfg" => {
+                ATTR_CFG => {
                     cfgs.push(meta);
                     continue;
                 }
                 _ => {
+                    unknown_attrs.push((name.clone(), meta_span));
                     continue;
                 }
             };
         }
+        if items.is_empty() {
+            for (name, span) in &unknown_attrs {
+                if let Some(suggestion) = suggest_item_attr(&name.to_string()) {
+                    return Err(Diagnostic::span_error(
+                        *span,
+                        format!(
+                            "unknown attribute `{}`; did you mean `{}`?",
+                            name, suggestion
+                        ),
+                    ));
+                }
+            }
+        }
         for (item, meta) in items {
-            self.add_item(item, cfgs.clone(), meta)?;
+            self.add_item(item.with_doc(doc.clone()), cfgs.clone(), meta)?;
         }
         let mut i = 0;
         let mut attr_idxs = &*attr_idxs;
@@ -191,9 +447,82 @@ impl Module {
     }
 }
 
+/// Wraps `item_ident` so that the generated callable emits a `DeprecationWarning`
+/// (equivalent to `warnings.warn(note, DeprecationWarning, stacklevel=2)`) before
+/// dispatching to the real implementation.
+fn deprecate_function(item_ident: &Ident, deprecation: &Deprecation) -> TokenStream2 {
+    let note = &deprecation.note;
+    quote_spanned! { item_ident.span() =>
+        {
+            let __inner = ::rustpython_vm::function::IntoPyNativeFunc::into_func(#item_ident);
+            move |vm: &::rustpython_vm::vm::VirtualMachine, args: ::rustpython_vm::function::FuncArgs| {
+                vm.warn(vm.ctx.exceptions.deprecation_warning.clone(), #note.to_owned(), 2)?;
+                __inner(vm, args)
+            }
+        }
+    }
+}
+
+/// Sets `__deprecated__` on the just-built object so tooling can introspect the
+/// `since`/`note` without calling the object. `best_effort` must be `true` for plain
+/// evaluated `#[pyattr]` values (bools/ints/strs/...), which unlike functions and
+/// classes aren't guaranteed to support setting an arbitrary attribute: rather than
+/// either panicking on that failure or making every `#[pymodule]`'s generated
+/// `extend_module`/`make_module` fallible just to propagate it with `?`, the set is
+/// attempted and a failure is silently ignored.
+fn deprecated_marker(deprecation: &Deprecation, span: Span, best_effort: bool) -> TokenStream2 {
+    let since = deprecation.since.clone().unwrap_or_default();
+    let note = deprecation.note.clone();
+    let set_attr = quote_spanned! { span =>
+        vm.set_attr(
+            &__obj,
+            "__deprecated__",
+            vm.new_pyobj((#since.to_owned(), #note.to_owned())),
+        )
+    };
+    if best_effort {
+        quote_spanned! { span => let _ = #set_attr; }
+    } else {
+        quote_spanned! { span => #set_attr.unwrap(); }
+    }
+}
+
+/// Sets `__doc__` from the item's `///` comments, unless something (e.g. an explicit
+/// hand-written docstring) already set one. Type objects built by `make_class` always
+/// expose a `__doc__` attribute (defaulting to `None`), so a missing docstring isn't
+/// detected by `get_attr` failing — it has to be checked for `None`-ness instead.
+///
+/// Not used for `ModuleItem::EvaluatedAttr`: those build a plain value (bool/int/str/...)
+/// that, unlike a function or class object, isn't guaranteed to support setting an
+/// arbitrary attribute, so applying a `///` comment there could panic at import time.
+fn doc_setter(doc: &Option<String>, span: Span) -> Option<TokenStream2> {
+    doc.as_ref().map(|doc| {
+        quote_spanned! { span =>
+            let __has_doc = vm
+                .get_attr(&__obj, "__doc__")
+                .map(|existing| !vm.is_none(&existing))
+                .unwrap_or(false);
+            if !__has_doc {
+                vm.set_attr(&__obj, "__doc__", vm.new_pyobj(#doc.to_owned())).unwrap();
+            }
+        }
+    })
+}
+
+/// Binds the single `__obj` built for a `#[py*]` item under each of its `py_names`
+/// (the primary name plus any aliases), so they all refer to the same object.
+fn bind_under_names(py_names: &[String]) -> impl Iterator<Item = TokenStream2> + '_ {
+    py_names.iter().map(|py_name| {
+        quote! {
+            vm.__module_set_attr(&module, #py_name, __obj.clone()).unwrap();
+        }
+    })
+}
+
 fn extract_module_items(
     mut items: Vec<ItemIdent>,
     module_name: &str,
+    module_doc: &Option<String>,
 ) -> Result<TokenStream2, Diagnostic> {
     let mut diagnostics: Vec<Diagnostic> = Vec::new();
 
@@ -203,61 +532,137 @@ fn extract_module_items(
         push_diag_result!(diagnostics, module.extract_item_from_syn(item),);
     }
 
-    let functions = module
-        .items
-        .into_iter()
-        .map(|((_name, cfgs), item)| match item {
-            ModuleItem::Function {
-                item_ident,
-                py_name,
-            } => {
-                let new_func = quote_spanned!(
-                    item_ident.span() =>
-                        vm.ctx.new_function_named(#item_ident, #module_name.to_owned(), #py_name.to_owned()));
-                quote! {
-                    #( #[ #cfgs ])*
-                    vm.__module_set_attr(&module, #py_name, #new_func).unwrap();
+    let functions = module.items.into_iter().map(|(cfgs, item)| match item {
+        ModuleItem::Function {
+            item_ident,
+            py_names,
+            deprecation,
+            doc,
+        } => {
+            let primary_name = &py_names[0];
+            let func = match &deprecation {
+                Some(dep) => deprecate_function(&item_ident, dep),
+                None => quote_spanned!(item_ident.span() => #item_ident),
+            };
+            let new_func = quote_spanned!(
+                item_ident.span() =>
+                    vm.ctx.new_function_named(#func, #module_name.to_owned(), #primary_name.to_owned()));
+            let marker = deprecation
+                .as_ref()
+                .map(|dep| deprecated_marker(dep, item_ident.span(), false));
+            let doc_setter = doc_setter(&doc, item_ident.span());
+            let registrations = bind_under_names(&py_names);
+            quote! {
+                #( #[ #cfgs ])*
+                {
+                    let __obj = #new_func;
+                    #marker
+                    #doc_setter
+                    #(#registrations)*
                 }
             }
-            ModuleItem::EvaluatedAttr {
-                item_ident,
-                py_name,
-            } => {
-                let new_attr = quote_spanned!(
-                    item_ident.span() =>
-                        vm.new_pyobj(#item_ident(vm)));
-                quote! {
-                    #( #[ #cfgs ])*
-                    vm.__module_set_attr(&module, #py_name, #new_attr).unwrap();
+        }
+        ModuleItem::EvaluatedAttr {
+            item_ident,
+            py_names,
+            deprecation,
+            // Not documented: see the comment on `doc_setter` for why — a plain
+            // evaluated value isn't guaranteed to support setting `__doc__`.
+            doc: _doc,
+        } => {
+            // Best-effort (unlike `deprecate_function`'s `?`): a plain evaluated value
+            // isn't wrapped in a function call the way a deprecated function is, so
+            // there's nowhere to propagate a warning-turned-error to without making
+            // every `#[pymodule]`'s generated `extend_module`/`make_module` fallible.
+            // The warning is still raised; it's just not allowed to fail the import.
+            let warn = deprecation.as_ref().map(|dep| {
+                let note = &dep.note;
+                quote_spanned!(item_ident.span() =>
+                    let _ = vm.warn(vm.ctx.exceptions.deprecation_warning.clone(), #note.to_owned(), 2);)
+            });
+            let new_attr = quote_spanned!(
+                item_ident.span() => {
+                    #warn
+                    vm.new_pyobj(#item_ident(vm))
+                });
+            // Best-effort (unlike the Function/Class markers): a plain evaluated value
+            // isn't guaranteed to support setting an arbitrary attribute either, and
+            // for the same reason as `warn` above this can't propagate via `?`.
+            let marker = deprecation
+                .as_ref()
+                .map(|dep| deprecated_marker(dep, item_ident.span(), true));
+            let registrations = bind_under_names(&py_names);
+            quote! {
+                #( #[ #cfgs ])*
+                {
+                    let __obj = #new_attr;
+                    #marker
+                    #(#registrations)*
                 }
             }
-            ModuleItem::Class {
-                item_ident,
-                py_name,
-            } => {
-                let new_class = quote_spanned!(
-                    item_ident.span() =>
-                        #item_ident::make_class(&vm.ctx));
-                quote! {
-                    #( #[ #cfgs ])*
-                    vm.__module_set_attr(&module, #py_name, #new_class).unwrap();
+        }
+        ModuleItem::Class {
+            item_ident,
+            py_names,
+            deprecation,
+            doc,
+        } => {
+            let new_class = quote_spanned!(
+                item_ident.span() =>
+                    #item_ident::make_class(&vm.ctx));
+            // No runtime warning for classes, only the `__deprecated__` marker below;
+            // see the doc comment on `Module::extract_class`.
+            let marker = deprecation
+                .as_ref()
+                .map(|dep| deprecated_marker(dep, item_ident.span(), false));
+            let doc_setter = doc_setter(&doc, item_ident.span());
+            let registrations = bind_under_names(&py_names);
+            quote! {
+                #( #[ #cfgs ])*
+                {
+                    let __obj = #new_class;
+                    #marker
+                    #doc_setter
+                    #(#registrations)*
                 }
             }
-        });
+        }
+    });
 
     Diagnostic::from_vec(diagnostics)?;
 
+    let module_doc_setter = module_doc.as_ref().map(|doc| {
+        quote! {
+            vm.__module_set_attr(&module, "__doc__", vm.new_pyobj(#doc.to_owned())).unwrap();
+        }
+    });
+
     Ok(quote! {
+        #module_doc_setter
         #(#functions)*
     })
 }
 
-pub fn impl_pymodule(attr: AttributeArgs, item: Item) -> Result<TokenStream2, Diagnostic> {
-    let mut module = match item {
-        Item::Mod(m) => m,
-        other => bail_span!(other, "#[pymodule] can only be on a module declaration"),
+/// Recognizes (and strips) the attribute marking a nested `mod foo { ... }` as a
+/// `#[pymodule]` submodule of its parent. A submodule is spelled either as a bare
+/// `#[pymodule]`, same as at the top level, or the explicit `#[pymodule(sub)]`.
+fn take_submodule_marker(attrs: &mut Vec<syn::Attribute>) -> bool {
+    let idx = match attrs.iter().position(|attr| attr.path.is_ident("pymodule")) {
+        Some(idx) => idx,
+        None => return false,
     };
-    let module_name = def_to_name(&module.ident, "pymodule", attr)?;
+    attrs.remove(idx);
+    true
+}
+
+/// Expands a `#[pymodule]` module under its fully-dotted `module_name`, recursing
+/// into nested `#[pymodule(sub)]` modules first so their `MODULE_NAME` and the
+/// `module`/`__module__` seen by Python reflect the `parent.child` path.
+fn expand_pymodule(
+    module_name: String,
+    mut module: syn::ItemMod,
+) -> Result<syn::ItemMod, Diagnostic> {
+    let module_doc = doc_fragments_to_string(doc_fragments(&module.attrs));
 
     let (_, content) = match module.content.as_mut() {
         Some(c) => c,
@@ -267,6 +672,37 @@ pub fn impl_pymodule(attr: AttributeArgs, item: Item) -> Result<TokenStream2, Di
         ),
     };
 
+    let mut submodules = Vec::new();
+    let mut rest = Vec::new();
+    for item in content.drain(..) {
+        match item {
+            Item::Mod(mut inner) if take_submodule_marker(&mut inner.attrs) => {
+                submodules.push(inner);
+            }
+            other => rest.push(other),
+        }
+    }
+    *content = rest;
+
+    let mut submodule_registrations = TokenStream2::new();
+    for submodule in submodules {
+        let cfgs: Vec<Meta> = submodule
+            .attrs
+            .iter()
+            .filter_map(|attr| attr.parse_meta().ok())
+            .filter(|meta| meta.path().is_ident("cfg"))
+            .collect();
+        let child_ident = submodule.ident.clone();
+        let child_name = child_ident.to_string();
+        let dotted_name = format!("{}.{}", module_name, child_name);
+        let expanded = expand_pymodule(dotted_name, submodule)?;
+        submodule_registrations.extend(quote! {
+            #( #[ #cfgs ])*
+            vm.__module_set_attr(&module, #child_name, #child_ident::make_module(vm)).unwrap();
+        });
+        content.push(Item::Mod(expanded));
+    }
+
     let items = content
         .iter_mut()
         .filter_map(|item| match item {
@@ -289,17 +725,19 @@ pub fn impl_pymodule(attr: AttributeArgs, item: Item) -> Result<TokenStream2, Di
         })
         .collect();
 
-    let extend_mod = extract_module_items(items, &module_name)?;
+    let extend_mod = extract_module_items(items, &module_name, &module_doc)?;
+
+    content.push(parse_quote! {
+        pub(crate) const MODULE_NAME: &str = #module_name;
+    });
     content.extend(vec![
-        parse_quote! {
-            pub(crate) const MODULE_NAME: &str = #module_name;
-        },
         parse_quote! {
             pub(crate) fn extend_module(
                 vm: &::rustpython_vm::vm::VirtualMachine,
                 module: &::rustpython_vm::pyobject::PyObjectRef,
             ) {
                 #extend_mod
+                #submodule_registrations
             }
         },
         parse_quote! {
@@ -314,5 +752,16 @@ pub fn impl_pymodule(attr: AttributeArgs, item: Item) -> Result<TokenStream2, Di
         },
     ]);
 
+    Ok(module)
+}
+
+pub fn impl_pymodule(attr: AttributeArgs, item: Item) -> Result<TokenStream2, Diagnostic> {
+    let module = match item {
+        Item::Mod(m) => m,
+        other => bail_span!(other, "#[pymodule] can only be on a module declaration"),
+    };
+    let module_name = def_to_name(&module.ident, "pymodule", attr)?;
+    let module = expand_pymodule(module_name, module)?;
+
     Ok(module.into_token_stream())
 }